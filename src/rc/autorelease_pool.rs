@@ -0,0 +1,170 @@
+use core::ffi::c_void;
+use core::marker::PhantomData;
+
+use crate::runtime;
+
+/// A handle to an active autorelease pool.
+///
+/// This is handed to the closure passed to [`autoreleasepool`], and is what
+/// bounds the lifetime of references handed out by e.g.
+/// [`Retained::autorelease`][super::Retained::autorelease]: such a
+/// reference may not outlive the pool it was autoreleased into.
+///
+/// `AutoreleasePool` is neither [`Send`] nor [`Sync`]: autorelease pools are
+/// a per-thread stack maintained by the Objective-C runtime, so a pool
+/// pushed on one thread must also be popped on that same thread, and in the
+/// right (innermost-first) order.
+pub struct AutoreleasePool {
+    context: *mut c_void,
+    _not_send_sync: PhantomData<*mut c_void>,
+}
+
+// Gated on the `std` feature (in addition to `debug_assertions`): the
+// nesting check needs a per-thread stack, and `std::thread_local!` is the
+// only portable way to get one - `alloc`-only builds skip this bookkeeping
+// and `verify_is_innermost` becomes a no-op there.
+#[cfg(all(debug_assertions, feature = "std"))]
+mod debug_stack {
+    use std::cell::RefCell;
+    use std::ffi::c_void;
+    use std::vec::Vec;
+
+    std::thread_local! {
+        /// The contexts of the autorelease pools currently pushed on this
+        /// thread, innermost last.
+        static POOL_STACK: RefCell<Vec<*mut c_void>> = const { RefCell::new(Vec::new()) };
+    }
+
+    pub(super) fn push(context: *mut c_void) {
+        POOL_STACK.with(|stack| stack.borrow_mut().push(context));
+    }
+
+    pub(super) fn pop(context: *mut c_void) {
+        POOL_STACK.with(|stack| {
+            let popped = stack.borrow_mut().pop();
+            debug_assert_eq!(
+                popped,
+                Some(context),
+                "autorelease pools must be popped in the reverse order they were pushed in"
+            );
+        });
+    }
+
+    pub(super) fn assert_is_innermost(context: *mut c_void) {
+        POOL_STACK.with(|stack| {
+            assert_eq!(
+                stack.borrow().last().copied(),
+                Some(context),
+                "attempted to autorelease into an outer `autoreleasepool` \
+                 while a nested `autoreleasepool` call was active; the \
+                 returned reference's true lifetime doesn't extend that far"
+            );
+        });
+    }
+}
+
+impl AutoreleasePool {
+    #[doc(alias = "objc_autoreleasePoolPush")]
+    fn push() -> Self {
+        // SAFETY: `objc_autoreleasePoolPush` may always be called; it just
+        // pushes a new marker onto the current thread's autorelease pool
+        // stack.
+        let context = unsafe { runtime::objc_autoreleasePoolPush() };
+        #[cfg(all(debug_assertions, feature = "std"))]
+        debug_stack::push(context);
+        Self {
+            context,
+            _not_send_sync: PhantomData,
+        }
+    }
+
+    /// Asserts that `self` is the innermost currently-active autorelease
+    /// pool on this thread.
+    ///
+    /// Only actually checks anything in debug builds with the `std` feature
+    /// enabled (this needs a per-thread stack); it's a no-op otherwise.
+    ///
+    /// Used by [`Retained::autorelease`][super::Retained::autorelease] (and
+    /// the other autoreleasing methods) to catch the case where an object is
+    /// autoreleased with an outer pool's handle while a nested
+    /// `autoreleasepool` call is active: the object then goes into the
+    /// nested pool, so the returned `&'p T` only really lives until the
+    /// nested pool's drain, not for all of `'p`, which the type system can't
+    /// see.
+    #[inline]
+    pub(crate) fn verify_is_innermost(&self) {
+        #[cfg(all(debug_assertions, feature = "std"))]
+        debug_stack::assert_is_innermost(self.context);
+    }
+}
+
+impl Drop for AutoreleasePool {
+    #[doc(alias = "objc_autoreleasePoolPop")]
+    fn drop(&mut self) {
+        #[cfg(all(debug_assertions, feature = "std"))]
+        debug_stack::pop(self.context);
+        // SAFETY: `self.context` was obtained from a matching
+        // `objc_autoreleasePoolPush`, and pools are popped in reverse
+        // order of being pushed since `AutoreleasePool` isn't `Send`/`Sync`
+        // and this `drop` is the only place we pop.
+        unsafe { runtime::objc_autoreleasePoolPop(self.context) };
+    }
+}
+
+/// Runs `f` inside a newly pushed autorelease pool, draining the pool (and
+/// so running the `dealloc` of everything autoreleased into it) once `f`
+/// returns - or unwinds, since popping happens in [`AutoreleasePool`]'s
+/// `Drop` impl.
+///
+/// `&'p T` references obtained from calls like
+/// [`Retained::autorelease`][super::Retained::autorelease] are bound to the
+/// `pool` given to `f`. Autoreleasing with that `pool` while a nested
+/// `autoreleasepool` call is active is unsound, since the object goes into
+/// the nested pool and is released when that one drains. In debug builds
+/// with the `std` feature this is caught by an assertion in the
+/// autoreleasing method; in other builds it is not checked. Later uses of
+/// the references themselves are never checked.
+pub fn autoreleasepool<F, R>(f: F) -> R
+where
+    F: FnOnce(&AutoreleasePool) -> R,
+{
+    let pool = AutoreleasePool::push();
+    f(&pool)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::autoreleasepool;
+
+    #[test]
+    fn test_autoreleasepool() {
+        autoreleasepool(|_pool| {});
+        autoreleasepool(|pool| {
+            autoreleasepool(|_nested_pool| {});
+            // The outer pool is still the one that was most recently pushed
+            // and not yet popped, so this must not panic.
+            pool.verify_is_innermost();
+        });
+    }
+
+    #[cfg(all(debug_assertions, feature = "std"))]
+    #[test]
+    #[should_panic(expected = "nested `autoreleasepool` call was active")]
+    fn test_nested_use_after_escape_panics() {
+        autoreleasepool(|outer_pool| {
+            autoreleasepool(|_inner_pool| {
+                // `outer_pool` is no longer the innermost pool, so
+                // autoreleasing with it here would be unsound - this should
+                // be caught by the debug-mode nesting check.
+                outer_pool.verify_is_innermost();
+            });
+        });
+    }
+}
+
+// There is no compile-time check that rejects closures capturing a `&'p T`
+// tied to an *outer* pool. A marker-trait bound blanket-implemented for
+// `T: Send` would not provide one: `&'p U` is `Send` whenever `U: Sync`, so a
+// `Send` closure can still capture such a reference. The debug-mode check in
+// `Retained::autorelease` (see `AutoreleasePool::verify_is_innermost`) is the
+// only protection against this.
@@ -0,0 +1,142 @@
+use core::ffi::c_void;
+use core::mem;
+
+use super::{Owned, Retained, RetainedRef};
+
+/// Conversion of a Rust-owned, reference-counted handle to and from an
+/// opaque foreign pointer.
+///
+/// This is the standard pattern for stashing a Rust-owned value (here,
+/// [`Retained<T>`] or [`Owned<T>`]) inside a C/Objective-C struct, ivar, or
+/// associated object as a plain `void*`, and later recovering it. Doing
+/// this by hand with [`Retained::as_ptr`] and [`mem::forget`] is easy to
+/// get subtly wrong (forgetting to forget, or reclaiming the pointer
+/// twice); this trait gives the pattern a name and a single place to get
+/// right.
+pub trait ForeignOwnable {
+    /// A non-owning view of `Self`, returned by [`Self::borrow`].
+    type Borrowed<'a>
+    where
+        Self: 'a;
+
+    /// Converts `self` into an opaque foreign pointer.
+    ///
+    /// The retain count (or unique ownership, for [`Owned`]) that `self`
+    /// was holding is leaked into the returned pointer; call
+    /// [`Self::from_foreign`] to reclaim it.
+    fn into_foreign(self) -> *const c_void;
+
+    /// Reconstructs a `Self` previously turned into a foreign pointer by
+    /// [`Self::into_foreign`].
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been returned by a prior call to
+    /// [`Self::into_foreign`] on a value of this same type, and must not
+    /// have already been passed to `from_foreign`.
+    unsafe fn from_foreign(ptr: *const c_void) -> Self;
+
+    /// Borrows the value pointed to by `ptr`, without reclaiming its
+    /// ownership.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been returned by a prior call to
+    /// [`Self::into_foreign`] on a value of this same type, must not have
+    /// been passed to [`Self::from_foreign`] yet, and the foreign owner
+    /// must keep it alive and stable for all of `'a`.
+    unsafe fn borrow<'a>(ptr: *const c_void) -> Self::Borrowed<'a>;
+}
+
+// `T: Sized` (rather than the `?Sized` that `Retained<T>` usually allows):
+// `from_foreign`/`borrow` only ever see a thin `void*` coming back from the
+// foreign side, with no previously-seen `*const T` around whose metadata
+// could be reattached to it (contrast `Retained::retain`, which always has
+// one). Lifting this would need a way to recover `T`'s (statically unit, for
+// the thin/extern types `Retained` supports) metadata purely from its type,
+// which isn't expressible on stable Rust today.
+impl<T> ForeignOwnable for Retained<T> {
+    type Borrowed<'a>
+        = RetainedRef<'a, T>
+    where
+        T: 'a;
+
+    #[inline]
+    fn into_foreign(self) -> *const c_void {
+        let ptr = Retained::as_ptr(&self) as *const c_void;
+        mem::forget(self);
+        ptr
+    }
+
+    #[inline]
+    unsafe fn from_foreign(ptr: *const c_void) -> Self {
+        // SAFETY: `ptr` carries the +1 retain count that `into_foreign`
+        // leaked, and the caller upholds that it's not being reclaimed
+        // more than once.
+        unsafe { Retained::new(ptr as *const T) }
+    }
+
+    #[inline]
+    unsafe fn borrow<'a>(ptr: *const c_void) -> Self::Borrowed<'a> {
+        // SAFETY: The caller upholds that `ptr` stays a live, owned
+        // foreign pointer for all of `'a`, so reading through it without
+        // touching the retain count is sound.
+        unsafe { RetainedRef::from_raw(ptr as *const T) }
+    }
+}
+
+// See the `impl ForeignOwnable for Retained<T>` above for why `T: Sized`.
+impl<T> ForeignOwnable for Owned<T> {
+    type Borrowed<'a>
+        = &'a T
+    where
+        T: 'a;
+
+    #[inline]
+    fn into_foreign(self) -> *const c_void {
+        let ptr = &*self as *const T as *const c_void;
+        mem::forget(self);
+        ptr
+    }
+
+    #[inline]
+    unsafe fn from_foreign(ptr: *const c_void) -> Self {
+        // SAFETY: `ptr` carries the unique ownership that `into_foreign`
+        // leaked, and the caller upholds that it's not being reclaimed
+        // more than once.
+        unsafe { Owned::new(ptr as *const T) }
+    }
+
+    #[inline]
+    unsafe fn borrow<'a>(ptr: *const c_void) -> Self::Borrowed<'a> {
+        // SAFETY: The caller upholds that `ptr` stays a live, owned
+        // foreign pointer for all of `'a`.
+        unsafe { &*(ptr as *const T) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::Object;
+
+    #[cfg(any(target_os = "macos", target_os = "ios"))]
+    #[test]
+    fn test_retained_round_trip() {
+        let obj: &Object = unsafe { msg_send![class!(NSObject), new] };
+        let obj: Retained<Object> = unsafe { Retained::new(obj) };
+        assert!(obj.retain_count() == 1);
+
+        let ptr = obj.into_foreign();
+
+        // Borrowing doesn't touch the retain count; `retain_count` is only
+        // defined on `Retained`, so upgrade first to check it.
+        let borrowed: RetainedRef<'_, Object> = unsafe { Retained::<Object>::borrow(ptr) };
+        let upgraded = borrowed.retain();
+        assert!(upgraded.retain_count() == 2);
+        drop(upgraded);
+
+        let obj: Retained<Object> = unsafe { Retained::from_foreign(ptr) };
+        assert!(obj.retain_count() == 1);
+    }
+}
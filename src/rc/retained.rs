@@ -32,7 +32,7 @@ use crate::runtime::{self, Object};
 ///
 /// TODO: Explain similarities with `Arc` and `RefCell`.
 #[repr(transparent)]
-pub struct Retained<T> {
+pub struct Retained<T: ?Sized> {
     /// A pointer to the contained object.
     ///
     /// It is important that this is `NonNull`, since we want to dereference
@@ -46,8 +46,12 @@ pub struct Retained<T> {
     /// }
     /// ```
     ///
-    /// DSTs that carry metadata cannot be used here, so unsure if we should
-    /// have a `?Sized` bound?
+    /// Both of these are thin (their pointer metadata is `()`). Other
+    /// `T: ?Sized` can be used too, but the runtime only ever hands us and
+    /// takes back a bare `*mut objc_object`, so any
+    /// metadata attached to `T` has to be something *we* keep track of, not
+    /// something the runtime round-trips for us. See [`Self::retain`] for how
+    /// that round-trip is done.
     ///
     /// TODO:
     /// https://doc.rust-lang.org/book/ch19-04-advanced-types.html#dynamically-sized-types-and-the-sized-trait
@@ -68,7 +72,7 @@ pub struct Retained<T> {
 /// Additiontally, it requires `T: Send` because if `T: !Send`, you could
 /// clone a `Retained`, send it to another thread, and drop the clone last,
 /// making `dealloc` get called on the other thread, violating `T: !Send`.
-unsafe impl<T: Sync + Send> Send for Retained<T> {}
+unsafe impl<T: ?Sized + Sync + Send> Send for Retained<T> {}
 
 /// The `Sync` implementation requires `T: Sync` because `&Retained` gives
 /// access to `&T`.
@@ -76,9 +80,9 @@ unsafe impl<T: Sync + Send> Send for Retained<T> {}
 /// Additiontally, it requires `T: Send`, because if `T: !Send`, you could
 /// clone a `&Retained` from another thread, and drop the clone last, making
 /// `dealloc` get called on the other thread, violating `T: !Send`.
-unsafe impl<T: Sync + Send> Sync for Retained<T> {}
+unsafe impl<T: ?Sized + Sync + Send> Sync for Retained<T> {}
 
-impl<T> Retained<T> {
+impl<T: ?Sized> Retained<T> {
     /// Constructs a `Retained<T>` to an object that already has a +1 retain
     /// count. This will not retain the object.
     ///
@@ -141,21 +145,70 @@ impl<T> Retained<T> {
     // Inlined since it's `objc_retain` that does the work.
     #[cfg_attr(debug_assertions, inline)]
     pub unsafe fn retain(ptr: *const T) -> Self {
+        // `objc_retain` only knows about the thin `objc_object` pointer, so
+        // strip `ptr` down to that before handing it to the runtime...
+        let thin = ptr as *mut Object;
         // SAFETY: The caller upholds that the pointer is valid
-        let rtn = runtime::objc_retain(ptr as *mut Object) as *const T;
-        debug_assert_eq!(rtn, ptr);
+        let rtn = runtime::objc_retain(thin);
+        debug_assert_eq!(rtn, thin);
         Self {
-            // SAFETY: Non-null upheld by the caller and `objc_retain` always
-            // returns the same pointer.
-            ptr: NonNull::new_unchecked(rtn as *mut T),
+            // ...and reconstruct the result from the *original* `ptr`
+            // instead of from `rtn`: `rtn` is thin, so casting it straight to
+            // `*mut T` would silently drop (or rather, fail to attach) any
+            // pointer metadata `T` carries. Since `objc_retain` is guaranteed
+            // to return the same object it was given (checked above), `ptr`
+            // is exactly the right pointer, metadata and all, to keep using.
+            //
+            // For thin/extern types this metadata is trivially `()`, so this
+            // is just a copy of `ptr` in that case.
+            //
+            // SAFETY: Non-null upheld by the caller.
+            ptr: NonNull::new_unchecked(ptr as *mut T),
             phantom: PhantomData,
         }
     }
 
-    /// TODO
+    /// Retains a value that was just returned to us autoreleased from an
+    /// Objective-C method (one annotated `ns_returns_autoreleased`), using
+    /// the ARC "retained return value" optimization where possible.
+    ///
+    /// Normally, an autoreleased return value would have to be retained with
+    /// a plain [`Self::retain`], and would then sit in the autorelease pool
+    /// until it drains. Since this is a very common pattern at the boundary
+    /// of every `msg_send!` call, the runtime instead lets the callee
+    /// (`objc_autoreleaseReturnValue`, see [`Self::autorelease_return`]) and
+    /// the caller (this function, `objc_retainAutoreleasedReturnValue`)
+    /// recognize each other: the runtime inspects the return address for a
+    /// specific marker instruction emitted right after the call (e.g. a
+    /// no-op `mov x29, x29` on arm64, or a particular `movq` on x86-64), and
+    /// if found, hands the object back at +1 directly, skipping the
+    /// autorelease pool entirely.
+    ///
+    /// This only works when the `msg_send!` and this call are truly
+    /// adjacent, with no other code (and in particular no other call that
+    /// could clobber the return address check) in between - something we
+    /// can't guarantee across an arbitrary, possibly-not-inlined Rust
+    /// function boundary. So this is `#[inline(always)]`, to give the best
+    /// chance of the call sites actually being adjacent. On the
+    /// architectures where the marker is known it calls
+    /// `objc_retainAutoreleasedReturnValue`, which acts like a plain
+    /// [`runtime::objc_retain`] when the marker isn't found; everywhere else
+    /// it calls [`runtime::objc_retain`] directly. Both are correct, the
+    /// slow path just actually goes through the autorelease pool.
     #[doc(alias = "objc_retainAutoreleasedReturnValue")]
-    pub unsafe fn retain_autoreleased_return(_obj: *const T) -> Self {
-        todo!()
+    #[inline(always)]
+    pub unsafe fn retain_autoreleased_return(obj: *const T) -> Self {
+        let thin = obj as *mut Object;
+        // SAFETY: The caller upholds that the pointer is valid
+        let rtn = retain_autoreleased_return_value(thin);
+        debug_assert_eq!(rtn, thin);
+        Self {
+            // See `Self::retain` for why we reconstruct from `obj` and not
+            // from `rtn`.
+            // SAFETY: Non-null upheld by the caller.
+            ptr: NonNull::new_unchecked(obj as *mut T),
+            phantom: PhantomData,
+        }
     }
 
     /// Autoreleases the retained pointer, meaning that the object is not
@@ -164,7 +217,12 @@ impl<T> Retained<T> {
     #[doc(alias = "objc_autorelease")]
     #[must_use = "If you don't intend to use the object any more, just drop it as usual"]
     #[inline]
-    pub fn autorelease<'p>(self, _pool: &'p AutoreleasePool) -> &'p T {
+    pub fn autorelease<'p>(self, pool: &'p AutoreleasePool) -> &'p T {
+        // In debug builds, catch the case where `pool` isn't actually the
+        // innermost pool any more (e.g. because a nested `autoreleasepool`
+        // call is in progress), which would mean the `&'p T` we're about to
+        // hand out doesn't really live as long as `'p` claims.
+        pool.verify_is_innermost();
         let ptr = mem::ManuallyDrop::new(self).ptr;
         // SAFETY: The `ptr` is guaranteed to be valid and have at least one
         // retain count.
@@ -175,31 +233,74 @@ impl<T> Retained<T> {
         unsafe { &*ptr.as_ptr() }
     }
 
-    /// TODO
+    /// Autoreleases the retained pointer as a return value, using
+    /// `objc_autoreleaseReturnValue`, which looks for the caller's marker so
+    /// that a caller's [`Self::retain_autoreleased_return`] (or the
+    /// Objective-C runtime's own `objc_retainAutoreleasedReturnValue`) can
+    /// skip the autorelease pool, as described there.
+    ///
+    /// As with the receiving side, this is only the real optimization when
+    /// inlined directly into the function returning the value; otherwise it
+    /// degrades gracefully to a normal [`Self::autorelease`].
     #[doc(alias = "objc_autoreleaseReturnValue")]
-    pub fn autorelease_return<'p>(self, _pool: &'p AutoreleasePool) -> &'p T {
-        todo!()
+    #[must_use = "If you don't intend to use the object any more, just drop it as usual"]
+    #[inline(always)]
+    pub fn autorelease_return<'p>(self, pool: &'p AutoreleasePool) -> &'p T {
+        // See `Self::autorelease` for why this check matters.
+        pool.verify_is_innermost();
+        let ptr = mem::ManuallyDrop::new(self).ptr;
+        // SAFETY: The `ptr` is guaranteed to be valid and have at least one
+        // retain count, and the `ManuallyDrop` means `drop` won't also
+        // release it.
+        unsafe { autorelease_return_value(ptr.as_ptr() as *mut Object) };
+        // SAFETY: The lifetime is bounded by the function signature.
+        unsafe { &*ptr.as_ptr() }
     }
 
-    /// TODO
+    /// Retains and immediately autoreleases `obj`, returning a reference
+    /// bound to `pool`.
     ///
-    /// Equivalent to `Retained::retain(&obj).autorelease(pool)`, but slightly
-    /// more efficient.
+    /// Equivalent to `Retained::retain(obj).autorelease(pool)`, but using
+    /// `objc_retainAutorelease`, which the runtime can implement slightly
+    /// more efficiently than the separate retain/autorelease pair (e.g. by
+    /// skipping a redundant push/pop of the same pool).
     #[doc(alias = "objc_retainAutorelease")]
-    pub unsafe fn retain_and_autorelease<'p>(_obj: *const T, _pool: &'p AutoreleasePool) -> &'p T {
-        todo!()
+    #[inline]
+    pub unsafe fn retain_and_autorelease<'p>(obj: *const T, pool: &'p AutoreleasePool) -> &'p T {
+        // See `Self::autorelease` for why this check matters.
+        pool.verify_is_innermost();
+        let thin = obj as *mut Object;
+        // SAFETY: The caller upholds that the pointer is valid.
+        unsafe { runtime::objc_retainAutorelease(thin) };
+        // SAFETY: The lifetime is bounded by the function signature, and
+        // `obj` is kept alive by the retain we just performed until the
+        // pool is drained.
+        unsafe { &*obj }
     }
 
-    /// TODO
+    /// Retains and immediately autoreleases `obj` as a return value,
+    /// combining [`Self::retain_and_autorelease`] with the
+    /// return-value-optimization marker from
+    /// [`Self::retain_autoreleased_return`].
     ///
-    /// Equivalent to `Retained::retain(&obj).autorelease_return(pool)`, but
-    /// slightly more efficient.
+    /// Equivalent to `Retained::retain(obj).autorelease_return(pool)`, but
+    /// slightly more efficient, for the same reasons as
+    /// [`Self::retain_and_autorelease`].
     #[doc(alias = "objc_retainAutoreleaseReturnValue")]
+    #[inline(always)]
     pub unsafe fn retain_and_autorelease_return<'p>(
-        _obj: *const T,
-        _pool: &'p AutoreleasePool,
+        obj: *const T,
+        pool: &'p AutoreleasePool,
     ) -> &'p T {
-        todo!()
+        // See `Self::autorelease` for why this check matters.
+        pool.verify_is_innermost();
+        let thin = obj as *mut Object;
+        // SAFETY: The caller upholds that the pointer is valid.
+        unsafe { retain_autorelease_return_value(thin) };
+        // SAFETY: The lifetime is bounded by the function signature, and
+        // `obj` is kept alive by the retain we just performed until the
+        // pool is drained.
+        unsafe { &*obj }
     }
 
     #[cfg(test)] // TODO
@@ -218,12 +319,77 @@ impl<T> Retained<T> {
 //     }
 // }
 
+/// Architectures where the ARC runtime looks for a marker instruction right
+/// after the call site to decide whether a retained return value can skip
+/// the autorelease pool.
+///
+/// On any other architecture there is no marker to look for, so we always
+/// take the (still correct) slow path below.
+#[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+const HAS_RETURN_VALUE_MARKER: bool = true;
+#[cfg(not(any(target_arch = "aarch64", target_arch = "x86_64")))]
+const HAS_RETURN_VALUE_MARKER: bool = false;
+
+/// See [`Retained::retain_autoreleased_return`].
+///
+/// # Safety
+///
+/// Same as [`runtime::objc_retain`]: `obj` must be a valid object pointer.
+#[inline(always)]
+unsafe fn retain_autoreleased_return_value(obj: *mut Object) -> *mut Object {
+    if HAS_RETURN_VALUE_MARKER {
+        // SAFETY: Upheld by the caller. If the marker isn't found (because
+        // we didn't actually get inlined right after the producing
+        // `msg_send!`), the runtime falls back to treating this exactly
+        // like `objc_retain`.
+        unsafe { runtime::objc_retainAutoreleasedReturnValue(obj) }
+    } else {
+        // SAFETY: Upheld by the caller. Semantically equivalent to the
+        // above, just without the chance of hitting the fast path.
+        unsafe { runtime::objc_retain(obj) }
+    }
+}
+
+/// See [`Retained::autorelease_return`].
+///
+/// # Safety
+///
+/// Same as [`runtime::objc_autorelease`]: `obj` must be a valid object
+/// pointer with at least one retain count that we're giving up.
+#[inline(always)]
+unsafe fn autorelease_return_value(obj: *mut Object) {
+    if HAS_RETURN_VALUE_MARKER {
+        // SAFETY: Upheld by the caller.
+        unsafe { runtime::objc_autoreleaseReturnValue(obj) }
+    } else {
+        // SAFETY: Upheld by the caller.
+        unsafe { runtime::objc_autorelease(obj) }
+    }
+}
+
+/// See [`Retained::retain_and_autorelease_return`].
+///
+/// # Safety
+///
+/// Same as [`runtime::objc_retain`] followed by [`runtime::objc_autorelease`]:
+/// `obj` must be a valid object pointer.
+#[inline(always)]
+unsafe fn retain_autorelease_return_value(obj: *mut Object) {
+    if HAS_RETURN_VALUE_MARKER {
+        // SAFETY: Upheld by the caller.
+        unsafe { runtime::objc_retainAutoreleaseReturnValue(obj) }
+    } else {
+        // SAFETY: Upheld by the caller.
+        unsafe { runtime::objc_retainAutorelease(obj) }
+    }
+}
+
 /// `#[may_dangle]` (see [this][dropck_eyepatch]) doesn't really make sense
 /// here, since we actually want to disallow creating `Retained` pointers to
 /// objects that have a `Drop` implementation.
 ///
 /// [dropck_eyepatch]: https://doc.rust-lang.org/nightly/nomicon/dropck.html#an-escape-hatch
-impl<T> Drop for Retained<T> {
+impl<T: ?Sized> Drop for Retained<T> {
     /// Releases the retained object
     #[doc(alias = "objc_release")]
     #[doc(alias = "release")]
@@ -235,7 +401,7 @@ impl<T> Drop for Retained<T> {
     }
 }
 
-impl<T> Clone for Retained<T> {
+impl<T: ?Sized> Clone for Retained<T> {
     /// Makes a clone of the `Retained` object.
     ///
     /// This increases the object's reference count.
@@ -248,7 +414,7 @@ impl<T> Clone for Retained<T> {
     }
 }
 
-impl<T> Deref for Retained<T> {
+impl<T: ?Sized> Deref for Retained<T> {
     type Target = T;
 
     #[inline]
@@ -258,7 +424,7 @@ impl<T> Deref for Retained<T> {
     }
 }
 
-impl<T: PartialEq> PartialEq for Retained<T> {
+impl<T: ?Sized + PartialEq> PartialEq for Retained<T> {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
         &**self == &**other
@@ -272,37 +438,37 @@ impl<T: PartialEq> PartialEq for Retained<T> {
 
 // TODO: impl PartialOrd, Ord and Eq
 
-impl<T: fmt::Display> fmt::Display for Retained<T> {
+impl<T: ?Sized + fmt::Display> fmt::Display for Retained<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Display::fmt(&**self, f)
     }
 }
 
-impl<T: fmt::Debug> fmt::Debug for Retained<T> {
+impl<T: ?Sized + fmt::Debug> fmt::Debug for Retained<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Debug::fmt(&**self, f)
     }
 }
 
-impl<T> fmt::Pointer for Retained<T> {
+impl<T: ?Sized> fmt::Pointer for Retained<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         fmt::Pointer::fmt(&self.ptr.as_ptr(), f)
     }
 }
 
-impl<T: hash::Hash> hash::Hash for Retained<T> {
+impl<T: ?Sized + hash::Hash> hash::Hash for Retained<T> {
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
         (&**self).hash(state)
     }
 }
 
-impl<T> borrow::Borrow<T> for Retained<T> {
+impl<T: ?Sized> borrow::Borrow<T> for Retained<T> {
     fn borrow(&self) -> &T {
         &**self
     }
 }
 
-impl<T> AsRef<T> for Retained<T> {
+impl<T: ?Sized> AsRef<T> for Retained<T> {
     fn as_ref(&self) -> &T {
         &**self
     }
@@ -310,20 +476,130 @@ impl<T> AsRef<T> for Retained<T> {
 
 // TODO: CoerceUnsized?
 
-impl<T> Unpin for Retained<T> {}
+impl<T: ?Sized> Unpin for Retained<T> {}
 
-impl<T> From<Owned<T>> for Retained<T> {
+impl<T: ?Sized> From<Owned<T>> for Retained<T> {
     fn from(obj: Owned<T>) -> Self {
         // SAFETY: TODO
         unsafe { Self::new(&*obj) }
     }
 }
 
+impl<T: ?Sized> Retained<T> {
+    /// Borrows `self` as a [`RetainedRef`], without touching the retain
+    /// count.
+    ///
+    /// Useful for passing an existing `Retained<T>` into a function that
+    /// only needs to borrow it for the duration of the call: unlike
+    /// `&Retained<T>`, the callee can cheaply promote the borrow to an
+    /// owned `Retained<T>` with [`RetainedRef::retain`] if it turns out it
+    /// needs to keep the object around longer than the call.
+    ///
+    /// Named `as_retained_ref` rather than `as_ref` so that it doesn't
+    /// shadow the inherent-method-wins-over-trait-method
+    /// [`AsRef::as_ref`] impl a few lines up, which callers (and generic
+    /// `impl AsRef<T>` code) reasonably expect to keep returning `&T`.
+    #[inline]
+    pub fn as_retained_ref(&self) -> RetainedRef<'_, T> {
+        // SAFETY: `self` keeps the object alive for at least the lifetime
+        // of the returned borrow.
+        unsafe { RetainedRef::from_raw(self.as_ptr()) }
+    }
+}
+
+/// A borrowed reference to a [`Retained<T>`], carrying a lifetime but
+/// performing no `retain`/`release` traffic of its own.
+///
+/// This is the `objc` equivalent of the Rust-for-Linux's `ArcBorrow`: it
+/// lets an API accept "an existing `Retained<T>` that I only need for the
+/// duration of this call" without paying for a `retain` and a matching
+/// `release` just to satisfy the type system, while still letting the
+/// callee cheaply upgrade to an owned [`Retained<T>`] via [`Self::retain`]
+/// if it turns out it needs to keep the object around for longer.
+///
+/// This is guaranteed to have the same size as the underlying pointer.
+#[repr(transparent)]
+pub struct RetainedRef<'a, T: ?Sized> {
+    ptr: NonNull<T>,
+    phantom: PhantomData<&'a Retained<T>>,
+}
+
+/// `RetainedRef` gives access to `&T`, so this requires `T: Sync`, like a
+/// plain `&'a T` would. It also requires `T: Send`, mirroring `Retained`'s
+/// `Send` impl: [`RetainedRef::retain`] hands out an owned `Retained<T>` on
+/// whichever thread the `RetainedRef` is on, whose `release` may be the last.
+unsafe impl<'a, T: ?Sized + Sync + Send> Send for RetainedRef<'a, T> {}
+
+/// See the `Send` impl above; the same reasoning applies to shared access.
+unsafe impl<'a, T: ?Sized + Sync + Send> Sync for RetainedRef<'a, T> {}
+
+impl<'a, T: ?Sized> RetainedRef<'a, T> {
+    /// Constructs a `RetainedRef` from a raw pointer, without retaining.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be a valid object pointer, and the object must be kept
+    /// alive (e.g. by some existing retain count) for all of `'a`.
+    #[inline]
+    pub(crate) unsafe fn from_raw(ptr: *const T) -> Self {
+        Self {
+            // SAFETY: Upheld by the caller.
+            ptr: unsafe { NonNull::new_unchecked(ptr as *mut T) },
+            phantom: PhantomData,
+        }
+    }
+
+    /// Upgrades the borrow to an owned [`Retained<T>`], retaining the
+    /// object so that it can outlive `'a`.
+    #[doc(alias = "objc_retain")]
+    #[inline]
+    pub fn retain(self) -> Retained<T> {
+        // SAFETY: `self.ptr` is valid for at least `'a`, since it was
+        // borrowed from a live `Retained<T>`.
+        unsafe { Retained::retain(self.ptr.as_ptr()) }
+    }
+}
+
+impl<'a, T: ?Sized> Clone for RetainedRef<'a, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+// Cheap to copy around: it's just a pointer, with no reference-count
+// traffic attached to either side of the copy.
+impl<'a, T: ?Sized> Copy for RetainedRef<'a, T> {}
+
+impl<'a, T: ?Sized> Deref for RetainedRef<'a, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: `self.ptr` is valid for at least `'a`.
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<'a, T: ?Sized> fmt::Pointer for RetainedRef<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Pointer::fmt(&self.ptr.as_ptr(), f)
+    }
+}
+
+impl<'a, T: ?Sized> From<RetainedRef<'a, T>> for Retained<T> {
+    /// Equivalent to [`RetainedRef::retain`].
+    fn from(r: RetainedRef<'a, T>) -> Self {
+        r.retain()
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use core::mem;
     use core::mem::size_of;
 
-    use super::Retained;
+    use super::{Retained, RetainedRef};
     use crate::runtime::Object;
 
     pub struct TestType {
@@ -337,6 +613,10 @@ mod tests {
             size_of::<Option<Retained<TestType>>>(),
             size_of::<&TestType>()
         );
+        assert_eq!(
+            size_of::<RetainedRef<'_, TestType>>(),
+            size_of::<&TestType>()
+        );
     }
 
     #[cfg(any(target_os = "macos", target_os = "ios"))]
@@ -354,4 +634,70 @@ mod tests {
         drop(obj);
         assert!(cloned.retain_count() == 1);
     }
+
+    #[cfg(any(target_os = "macos", target_os = "ios"))]
+    #[test]
+    fn test_retain_autoreleased_return() {
+        use super::super::autoreleasepool;
+
+        autoreleasepool(|pool| {
+            let obj: &Object = unsafe { msg_send![class!(NSObject), new] };
+            let obj: Retained<Object> = unsafe { Retained::new(obj) };
+            assert!(obj.retain_count() == 1);
+
+            // Simulate receiving `obj` as an autoreleased return value from a
+            // `msg_send!` annotated `ns_returns_autoreleased`.
+            let autoreleased: &Object = obj.autorelease(pool);
+            assert!(autoreleased.retain_count() == 1);
+
+            let retained = unsafe { Retained::retain_autoreleased_return(autoreleased) };
+            assert!(retained.retain_count() == 2);
+            drop(retained);
+            assert!(autoreleased.retain_count() == 1);
+        });
+    }
+
+    #[cfg(any(target_os = "macos", target_os = "ios"))]
+    #[test]
+    fn test_retained_ref() {
+        let obj: &Object = unsafe { msg_send![class!(NSObject), new] };
+        let obj: Retained<Object> = unsafe { Retained::new(obj) };
+        assert!(obj.retain_count() == 1);
+
+        // Borrowing doesn't touch the retain count.
+        let borrowed = obj.as_retained_ref();
+        assert!(obj.retain_count() == 1);
+        assert_eq!(&*borrowed as *const Object, &*obj as *const Object);
+
+        // `RetainedRef` is `Copy`, and upgrading a copy doesn't consume it.
+        let upgraded = borrowed.retain();
+        assert!(obj.retain_count() == 2);
+        assert!(upgraded.retain_count() == 2);
+
+        drop(upgraded);
+        assert!(obj.retain_count() == 1);
+    }
+
+    trait Describe {
+        fn describe(&self) -> &'static str;
+    }
+
+    impl Describe for TestType {
+        fn describe(&self) -> &'static str {
+            "TestType"
+        }
+    }
+
+    #[test]
+    fn test_unsized() {
+        let obj = TestType { _data: [] };
+        // `Retained::new` never touches the runtime, so this is safe to call
+        // on a value that isn't really a registered Objective-C object.
+        let retained: Retained<dyn Describe> =
+            unsafe { Retained::new(&obj as *const dyn Describe) };
+        assert_eq!(retained.describe(), "TestType");
+        // Don't run `Drop` (i.e. `objc_release`) on a pointer that was never
+        // actually retained.
+        mem::forget(retained);
+    }
 }
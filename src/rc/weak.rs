@@ -0,0 +1,148 @@
+extern crate alloc;
+
+use alloc::boxed::Box;
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ptr;
+
+use super::Retained;
+use crate::runtime::{self, Object};
+
+/// A weak reference to an Objective-C object.
+///
+/// Unlike [`Retained`], holding a `WeakId` does not keep the referenced
+/// object alive: once the object's retain count drops to zero, its
+/// `dealloc` runs as usual, and the runtime then zeroes out every live
+/// `WeakId` that pointed to it, so [`Self::load`] reliably sees `None`
+/// instead of handing back a dangling pointer.
+///
+/// This is implemented on top of the runtime's zeroing weak references
+/// (`objc_initWeak`/`objc_loadWeakRetained`/`objc_copyWeak`/`objc_destroyWeak`),
+/// the same primitives that back `__weak` in Objective-C and ARC.
+///
+/// The storage slot that the runtime writes the zero into must not move
+/// once it has been registered with `objc_initWeak`, so it is boxed here;
+/// this keeps `WeakId` itself free to be moved around like any other Rust
+/// value. It is an `UnsafeCell` because the runtime can write to it (zeroing
+/// it out on deallocation) even while we only hold a shared `&WeakId`.
+pub struct WeakId<T: ?Sized> {
+    /// Boxed so the slot's address stays stable even if the `WeakId` is
+    /// moved; the runtime is holding a pointer to this exact location.
+    inner: Box<UnsafeCell<*mut Object>>,
+    phantom: PhantomData<T>,
+}
+
+/// The `Send` implementation requires `T: Sync` because `load` on a shared
+/// `&WeakId` can hand out a [`Retained<T>`], and `T: Send` because the
+/// object may end up being `dealloc`'d from whichever thread drops the last
+/// strong reference, mirroring the reasoning in [`Retained`]'s `Send`/`Sync`
+/// impls.
+unsafe impl<T: ?Sized + Sync + Send> Send for WeakId<T> {}
+
+/// See the `Send` impl above; the same reasoning applies to shared access.
+unsafe impl<T: ?Sized + Sync + Send> Sync for WeakId<T> {}
+
+impl<T: ?Sized> WeakId<T> {
+    /// Constructs a new `WeakId` referencing the given object.
+    #[doc(alias = "objc_initWeak")]
+    pub fn new(obj: &Retained<T>) -> Self {
+        let inner = Box::new(UnsafeCell::new(ptr::null_mut()));
+        // SAFETY: `inner` is freshly boxed, so its address is stable, and
+        // `obj.as_ptr()` is a valid object pointer since it comes from a
+        // live `Retained`.
+        unsafe { runtime::objc_initWeak(inner.get(), obj.as_ptr() as *mut Object) };
+        Self {
+            inner,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> WeakId<T> {
+    /// Attempts to load a strong, retained reference to the object.
+    ///
+    /// Returns `None` if the object has already been deallocated.
+    ///
+    /// Unlike most of `WeakId`'s API, this requires `T: Sized`: the runtime
+    /// only ever hands `objc_loadWeakRetained` back a thin pointer, and
+    /// unlike [`Retained::retain`] there is no previously-seen `*const T`
+    /// lying around whose metadata we could reattach to it, so there's no
+    /// sound way to reconstruct a genuinely fat pointer here.
+    #[doc(alias = "objc_loadWeakRetained")]
+    pub fn load(&self) -> Option<Retained<T>> {
+        // SAFETY: `inner` was registered with `objc_initWeak`, and the
+        // `UnsafeCell` reflects that the runtime may write through this
+        // pointer concurrently with other uses of `&self`.
+        let ptr = unsafe { runtime::objc_loadWeakRetained(self.inner.get()) };
+        if ptr.is_null() {
+            None
+        } else {
+            // SAFETY: `objc_loadWeakRetained` already performed the retain
+            // for us, so this hands off that +1 retain count.
+            Some(unsafe { Retained::new(ptr as *mut T) })
+        }
+    }
+}
+
+impl<T: ?Sized> Clone for WeakId<T> {
+    /// Makes a new weak reference pointing to the same object (or lack
+    /// thereof) as `self`.
+    #[doc(alias = "objc_copyWeak")]
+    fn clone(&self) -> Self {
+        let inner = Box::new(UnsafeCell::new(ptr::null_mut()));
+        // SAFETY: Both slots are boxed, so neither address moves; `self.inner`
+        // was previously registered with `objc_initWeak` (or `objc_copyWeak`).
+        unsafe { runtime::objc_copyWeak(inner.get(), self.inner.get()) };
+        Self {
+            inner,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized> Drop for WeakId<T> {
+    /// Deregisters the weak reference from the runtime.
+    #[doc(alias = "objc_destroyWeak")]
+    fn drop(&mut self) {
+        // SAFETY: `inner` was registered with `objc_initWeak`/`objc_copyWeak`
+        // and hasn't been destroyed yet.
+        unsafe { runtime::objc_destroyWeak(self.inner.get()) };
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for WeakId<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WeakId").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem::size_of;
+
+    use super::WeakId;
+    use crate::runtime::Object;
+
+    #[test]
+    fn test_size_of() {
+        assert_eq!(size_of::<WeakId<Object>>(), size_of::<*mut Object>());
+    }
+
+    #[cfg(any(target_os = "macos", target_os = "ios"))]
+    #[test]
+    fn test_load() {
+        use super::super::Retained;
+
+        let obj: &Object = unsafe { msg_send![class!(NSObject), new] };
+        let obj: Retained<Object> = unsafe { Retained::new(obj) };
+
+        let weak = WeakId::new(&obj);
+        // The object is still alive, so loading succeeds.
+        assert!(weak.load().is_some());
+
+        drop(obj);
+        // The runtime has zeroed out the slot, so loading now fails.
+        assert!(weak.load().is_none());
+    }
+}